@@ -1,15 +1,18 @@
 use std::fmt;
 use std::cmp::Ordering;
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub, Num, One, Signed, Zero};
 
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 enum Sign {
     Positive,
     Negative,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+// `digits` holds the magnitude as little-endian limbs in base 2^32 (like
+// num-bigint's `BigDigit` vector), with the sign tracked separately.
+#[derive(Debug, PartialEq, Eq, Clone)]
 struct BigInt {
-    digits: Vec<u8>,
+    digits: Vec<u32>,
     sign: Sign,
 }
 
@@ -34,44 +37,108 @@ impl BigInt {
             },
             _ => 0,
         };
+        if digits_start == s.len() {
+            return Err("Invalid argument");
+        }
 
-        let digits: Result<Vec<u8>, _> = s[digits_start..].chars().rev().map(|c| {
-            c.to_digit(10).ok_or("Invalid digit").map(|digit| digit as u8)
-        }).collect();
-
-        digits.map(|digits| BigInt { digits, sign })
+        let mut value = BigInt::new();
+        for c in s[digits_start..].chars() {
+            let digit = c.to_digit(10).ok_or("Invalid digit")?;
+            value.mul_add_small(10, digit);
+        }
+        value.sign = sign;
+        value.normalize();
+        Ok(value)
     }
 
     fn to_string(&self) -> String {
+        if self.digits.is_empty() {
+            return "0".to_string();
+        }
         let sign_str = match self.sign {
             Sign::Positive => "",
             Sign::Negative => "-",
         };
-        let digits_str: String = self.digits.iter().rev().map(|digit| digit.to_string()).collect::<Vec<_>>().join("");
-        if digits_str.is_empty() {
+
+        // Peel off base-10^9 chunks from least to most significant, then
+        // print the most significant chunk bare and the rest zero-padded.
+        let mut chunks = Vec::new();
+        let mut n = BigInt { digits: self.digits.clone(), sign: Sign::Positive };
+        while !n.digits.is_empty() {
+            let (q, r) = n.div_rem_small(1_000_000_000);
+            chunks.push(r);
+            n = q;
+        }
+
+        let mut digits_str = String::new();
+        for (i, chunk) in chunks.iter().rev().enumerate() {
+            if i == 0 {
+                digits_str.push_str(&chunk.to_string());
+            } else {
+                digits_str.push_str(&format!("{:09}", chunk));
+            }
+        }
+        format!("{}{}", sign_str, digits_str)
+    }
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<BigInt, &'static str> {
+        if !(2..=36).contains(&radix) {
+            return Err("Invalid radix");
+        }
+        if s.is_empty() {
+            return Err("Invalid argument");
+        }
+
+        let mut sign = Sign::Positive;
+        let digits_start = match s.chars().next().unwrap() {
+            '-' => {
+                sign = Sign::Negative;
+                1
+            },
+            _ => 0,
+        };
+        if digits_start == s.len() {
+            return Err("Invalid argument");
+        }
+
+        let mut value = BigInt::new();
+        for c in s[digits_start..].chars() {
+            let digit = c.to_digit(radix).ok_or("Invalid digit")?;
+            value.mul_add_small(radix, digit);
+        }
+        value.sign = sign;
+        value.normalize();
+        Ok(value)
+    }
+
+    fn to_str_radix(&self, radix: u32) -> String {
+        assert!((2..=36).contains(&radix), "Invalid radix");
+
+        if self.digits.is_empty() {
             return "0".to_string();
         }
+
+        let mut n = BigInt { digits: self.digits.clone(), sign: Sign::Positive };
+        let mut rev_digits = Vec::new();
+        while !n.digits.is_empty() {
+            let (q, r) = n.div_rem_small(radix);
+            rev_digits.push(std::char::from_digit(r, radix).unwrap());
+            n = q;
+        }
+
+        let sign_str = match self.sign {
+            Sign::Positive => "",
+            Sign::Negative => "-",
+        };
+        let digits_str: String = rev_digits.into_iter().rev().collect();
         format!("{}{}", sign_str, digits_str)
     }
 
     fn add(&self, b: &BigInt) -> BigInt {
         match (self.sign, b.sign) {
             (Sign::Positive, Sign::Positive) | (Sign::Negative, Sign::Negative) => {
-                let mut res = self.clone();
-                res.digits.resize(std::cmp::max(self.digits.len(), b.digits.len()) + 1, 0);
-                let mut carry = 0;
-                for (a, b) in res.digits.iter_mut().zip(b.digits.iter().cloned().chain(std::iter::repeat(0))) {
-                    *a += b + carry;
-                    if *a >= 10 {
-                        *a -= 10;
-                        carry = 1;
-                    } else {
-                        carry = 0;
-                    }
-                }
-                if carry > 0 {
-                    res.digits.push(carry);
-                }
+                let mut res = self.add_abs(b);
+                res.sign = self.sign;
                 res.normalize();
                 res
             },
@@ -122,26 +189,41 @@ impl BigInt {
         }
     }
 
+    // Adds magnitudes only (signs are handled by the caller).
+    fn add_abs(&self, b: &BigInt) -> BigInt {
+        let mut res = BigInt {
+            digits: vec![0u32; std::cmp::max(self.digits.len(), b.digits.len()) + 1],
+            sign: Sign::Positive,
+        };
+        let mut carry = 0u64;
+        for i in 0..res.digits.len() {
+            let x = *self.digits.get(i).unwrap_or(&0) as u64;
+            let y = *b.digits.get(i).unwrap_or(&0) as u64;
+            let sum = x + y + carry;
+            res.digits[i] = sum as u32;
+            carry = sum >> 32;
+        }
+        res.normalize();
+        res
+    }
+
+    // Subtracts magnitudes only; requires `|self| >= |other|`.
     fn sub_abs(&self, other: &BigInt) -> BigInt {
         let mut res = self.clone();
-        let mut borrow = 0;
-        for (a, b) in res.digits.iter_mut().zip(other.digits.iter().cloned().chain(std::iter::repeat(0))) {
-            let b = b + borrow;
-            borrow = if *a < b {
-                *a += 10;
-                1
+        res.sign = Sign::Positive;
+        let mut borrow = 0i64;
+        for i in 0..res.digits.len() {
+            let a = res.digits[i] as i64;
+            let b = *other.digits.get(i).unwrap_or(&0) as i64 + borrow;
+            if a < b {
+                res.digits[i] = (a + (1i64 << 32) - b) as u32;
+                borrow = 1;
             } else {
-                0
-            };
-            *a -= b;
-        }
-        while let Some(&last) = res.digits.last() {
-            if last == 0 {
-                res.digits.pop();
-            } else {
-                break;
+                res.digits[i] = (a - b) as u32;
+                borrow = 0;
             }
         }
+        res.normalize();
         res
     }
 
@@ -153,6 +235,241 @@ impl BigInt {
                 break;
             }
         }
+        // Canonicalize zero to `Sign::Positive` so `Zero`/equality agree
+        // regardless of which branch produced it (e.g. a negative dividend
+        // with a zero remainder).
+        if self.digits.is_empty() {
+            self.sign = Sign::Positive;
+        }
+    }
+
+    // Decimal/radix boundary helpers: everything above base 2^32 is
+    // translated through these so the rest of the arithmetic never has to
+    // think in any base but 2^32.
+    fn mul_add_small(&mut self, mul: u32, add: u32) {
+        let mut carry = add as u64;
+        for limb in self.digits.iter_mut() {
+            let cur = *limb as u64 * mul as u64 + carry;
+            *limb = cur as u32;
+            carry = cur >> 32;
+        }
+        while carry > 0 {
+            self.digits.push(carry as u32);
+            carry >>= 32;
+        }
+    }
+
+    fn div_rem_small(&self, divisor: u32) -> (BigInt, u32) {
+        let mut quotient = vec![0u32; self.digits.len()];
+        let mut rem = 0u64;
+        for i in (0..self.digits.len()).rev() {
+            let cur = (rem << 32) | self.digits[i] as u64;
+            quotient[i] = (cur / divisor as u64) as u32;
+            rem = cur % divisor as u64;
+        }
+        let mut q = BigInt { digits: quotient, sign: Sign::Positive };
+        q.normalize();
+        (q, rem as u32)
+    }
+
+    // Karatsuba is only a win once both operands have enough limbs to
+    // amortize the extra additions; below this the schoolbook loop wins.
+    const KARATSUBA_THRESHOLD: usize = 32;
+
+    fn mul(&self, b: &BigInt) -> BigInt {
+        let mut res = self.mul_abs(b);
+        res.sign = if self.sign == b.sign { Sign::Positive } else { Sign::Negative };
+        res.normalize();
+        res
+    }
+
+    fn mul_abs(&self, b: &BigInt) -> BigInt {
+        if self.digits.len() > Self::KARATSUBA_THRESHOLD && b.digits.len() > Self::KARATSUBA_THRESHOLD {
+            self.mul_karatsuba(b)
+        } else {
+            self.mul_schoolbook(b)
+        }
+    }
+
+    fn mul_schoolbook(&self, b: &BigInt) -> BigInt {
+        if self.digits.is_empty() || b.digits.is_empty() {
+            return BigInt::new();
+        }
+
+        // Each cell accumulates several u32*u32 partial products before any
+        // carry propagation, so it needs more headroom than a u64.
+        let mut acc = vec![0u128; self.digits.len() + b.digits.len()];
+        for (i, &x) in self.digits.iter().enumerate() {
+            for (j, &y) in b.digits.iter().enumerate() {
+                acc[i + j] += x as u128 * y as u128;
+            }
+        }
+
+        let mut digits = Vec::with_capacity(acc.len() + 1);
+        let mut carry = 0u128;
+        for cell in acc {
+            let cur = cell + carry;
+            digits.push(cur as u32);
+            carry = cur >> 32;
+        }
+        while carry > 0 {
+            digits.push(carry as u32);
+            carry >>= 32;
+        }
+
+        let mut out = BigInt { digits, sign: Sign::Positive };
+        out.normalize();
+        out
+    }
+
+    fn mul_karatsuba(&self, b: &BigInt) -> BigInt {
+        let m = std::cmp::min(self.digits.len(), b.digits.len()) / 2;
+
+        let (x0, x1) = self.split_at(m);
+        let (y0, y1) = b.split_at(m);
+
+        let z0 = x0.mul_abs(&y0);
+        let z2 = x1.mul_abs(&y1);
+        let z1 = x0.add(&x1).mul_abs(&y0.add(&y1)).sub(&z2).sub(&z0);
+
+        z2.shift_limbs(2 * m).add(&z1.shift_limbs(m)).add(&z0)
+    }
+
+    // Splits `self` into (low, high) around limb index `m`, i.e.
+    // `self == high * 2^(32*m) + low`.
+    fn split_at(&self, m: usize) -> (BigInt, BigInt) {
+        if m >= self.digits.len() {
+            return (self.clone(), BigInt::new());
+        }
+        let mut low = BigInt {
+            digits: self.digits[..m].to_vec(),
+            sign: Sign::Positive,
+        };
+        let mut high = BigInt {
+            digits: self.digits[m..].to_vec(),
+            sign: Sign::Positive,
+        };
+        low.normalize();
+        high.normalize();
+        (low, high)
+    }
+
+    // Multiplies by 2^(32*n) via prepending zero limbs.
+    fn shift_limbs(&self, n: usize) -> BigInt {
+        if self.digits.is_empty() || n == 0 {
+            return self.clone();
+        }
+        let mut digits = vec![0u32; n];
+        digits.extend_from_slice(&self.digits);
+        BigInt { digits, sign: self.sign }
+    }
+
+    // Shifts the magnitude left by one bit (multiplies by 2).
+    fn shl1(&self) -> BigInt {
+        let mut digits = Vec::with_capacity(self.digits.len() + 1);
+        let mut carry = 0u32;
+        for &limb in &self.digits {
+            digits.push((limb << 1) | carry);
+            carry = limb >> 31;
+        }
+        if carry > 0 {
+            digits.push(carry);
+        }
+        let mut res = BigInt { digits, sign: Sign::Positive };
+        res.normalize();
+        res
+    }
+
+    // Truncated division (quotient rounds toward zero, remainder carries the
+    // dividend's sign), matching Rust's built-in integer `/` and `%`. The
+    // magnitudes are divided by bit-by-bit restoring long division over the
+    // base-2^32 limbs (one compare-and-subtract per bit).
+    fn div_rem(&self, b: &BigInt) -> Result<(BigInt, BigInt), &'static str> {
+        if b.digits.is_empty() {
+            return Err("Division by zero");
+        }
+
+        let b_abs = BigInt { digits: b.digits.clone(), sign: Sign::Positive };
+        let mut quotient_digits = vec![0u32; self.digits.len()];
+        let mut r = BigInt::new();
+
+        for bit_index in (0..self.digits.len() * 32).rev() {
+            r = r.shl1();
+
+            let limb = self.digits[bit_index / 32];
+            let bit = (limb >> (bit_index % 32)) & 1;
+            if bit == 1 {
+                if r.digits.is_empty() {
+                    r.digits.push(1);
+                } else {
+                    r.digits[0] |= 1;
+                }
+            }
+
+            if b_abs.cmp_abs(&r) != Ordering::Greater {
+                r = r.sub_abs(&b_abs);
+                quotient_digits[bit_index / 32] |= 1 << (bit_index % 32);
+            }
+        }
+
+        let mut quotient = BigInt { digits: quotient_digits, sign: Sign::Positive };
+        quotient.normalize();
+        quotient.sign = if self.sign == b.sign { Sign::Positive } else { Sign::Negative };
+        quotient.normalize();
+
+        r.sign = self.sign;
+        r.normalize();
+
+        Ok((quotient, r))
+    }
+
+    fn div(&self, b: &BigInt) -> Result<BigInt, &'static str> {
+        self.div_rem(b).map(|(q, _)| q)
+    }
+
+    fn rem(&self, b: &BigInt) -> Result<BigInt, &'static str> {
+        self.div_rem(b).map(|(_, r)| r)
+    }
+
+    // Binary exponentiation: square the base each step, folding it into the
+    // accumulator whenever the current exponent bit is set.
+    fn pow(&self, exp: u64) -> BigInt {
+        let mut result = BigInt::from("1").unwrap();
+        let mut base = self.clone();
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.mul(&base);
+            }
+            base = base.mul(&base);
+            exp >>= 1;
+        }
+        result
+    }
+
+    // Floor integer square root via Newton's method, starting from an
+    // overestimate and iterating `x = (x + n/x) / 2` until it stops
+    // decreasing.
+    fn sqrt(&self) -> Result<BigInt, &'static str> {
+        if self.sign == Sign::Negative {
+            return Err("Square root of negative number");
+        }
+        if self.digits.is_empty() {
+            return Ok(BigInt::new());
+        }
+
+        let half_digits = self.to_string().len().div_ceil(2);
+        let mut x = BigInt::from_str_radix(&format!("1{}", "0".repeat(half_digits)), 10).unwrap();
+
+        loop {
+            let (q, _) = self.div_rem(&x)?;
+            let next = x.add(&q).div(&BigInt::from("2").unwrap())?;
+            if next.cmp_abs(&x) != Ordering::Less {
+                break;
+            }
+            x = next;
+        }
+        Ok(x)
     }
 }
 
@@ -162,6 +479,203 @@ impl fmt::Display for BigInt {
     }
 }
 
+impl std::ops::Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, b: &BigInt) -> BigInt {
+        BigInt::add(self, b)
+    }
+}
+
+impl std::ops::Add for BigInt {
+    type Output = BigInt;
+
+    fn add(self, b: BigInt) -> BigInt {
+        &self + &b
+    }
+}
+
+impl std::ops::Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, b: &BigInt) -> BigInt {
+        BigInt::sub(self, b)
+    }
+}
+
+impl std::ops::Sub for BigInt {
+    type Output = BigInt;
+
+    fn sub(self, b: BigInt) -> BigInt {
+        &self - &b
+    }
+}
+
+impl std::ops::Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, b: &BigInt) -> BigInt {
+        BigInt::mul(self, b)
+    }
+}
+
+impl std::ops::Mul for BigInt {
+    type Output = BigInt;
+
+    fn mul(self, b: BigInt) -> BigInt {
+        &self * &b
+    }
+}
+
+impl std::ops::Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        let mut res = self.clone();
+        if !res.digits.is_empty() {
+            res.sign = match res.sign {
+                Sign::Positive => Sign::Negative,
+                Sign::Negative => Sign::Positive,
+            };
+        }
+        res
+    }
+}
+
+impl std::ops::Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        -&self
+    }
+}
+
+impl std::ops::Div for &BigInt {
+    type Output = BigInt;
+
+    fn div(self, b: &BigInt) -> BigInt {
+        BigInt::div(self, b).expect("division by zero")
+    }
+}
+
+impl std::ops::Div for BigInt {
+    type Output = BigInt;
+
+    fn div(self, b: BigInt) -> BigInt {
+        &self / &b
+    }
+}
+
+impl std::ops::Rem for &BigInt {
+    type Output = BigInt;
+
+    fn rem(self, b: &BigInt) -> BigInt {
+        BigInt::rem(self, b).expect("division by zero")
+    }
+}
+
+impl std::ops::Rem for BigInt {
+    type Output = BigInt;
+
+    fn rem(self, b: BigInt) -> BigInt {
+        &self % &b
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &BigInt) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &BigInt) -> Ordering {
+        match (self.sign, other.sign) {
+            (Sign::Positive, Sign::Negative) => Ordering::Greater,
+            (Sign::Negative, Sign::Positive) => Ordering::Less,
+            (Sign::Positive, Sign::Positive) => self.cmp_abs(other),
+            (Sign::Negative, Sign::Negative) => other.cmp_abs(self),
+        }
+    }
+}
+
+impl Zero for BigInt {
+    fn zero() -> BigInt {
+        BigInt::new()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+}
+
+impl One for BigInt {
+    fn one() -> BigInt {
+        BigInt::from("1").unwrap()
+    }
+}
+
+impl Signed for BigInt {
+    fn abs(&self) -> BigInt {
+        BigInt { digits: self.digits.clone(), sign: Sign::Positive }
+    }
+
+    // The "positive difference": zero when `self <= other`, `self - other`
+    // otherwise.
+    fn abs_sub(&self, other: &BigInt) -> BigInt {
+        let diff = self.sub(other);
+        if diff.sign == Sign::Negative {
+            BigInt::new()
+        } else {
+            diff
+        }
+    }
+
+    fn signum(&self) -> BigInt {
+        if self.is_zero() {
+            BigInt::new()
+        } else if self.sign == Sign::Negative {
+            -BigInt::one()
+        } else {
+            BigInt::one()
+        }
+    }
+
+    fn is_positive(&self) -> bool {
+        self.sign == Sign::Positive && !self.is_zero()
+    }
+
+    fn is_negative(&self) -> bool {
+        self.sign == Sign::Negative
+    }
+}
+
+impl Num for BigInt {
+    type FromStrRadixErr = &'static str;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<BigInt, &'static str> {
+        BigInt::from_str_radix(s, radix)
+    }
+}
+
+impl CheckedAdd for BigInt {
+    fn checked_add(&self, b: &BigInt) -> Option<BigInt> {
+        Some(self.add(b))
+    }
+}
+
+impl CheckedSub for BigInt {
+    fn checked_sub(&self, b: &BigInt) -> Option<BigInt> {
+        Some(self.sub(b))
+    }
+}
+
+impl CheckedMul for BigInt {
+    fn checked_mul(&self, b: &BigInt) -> Option<BigInt> {
+        Some(self.mul(b))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -180,4 +694,172 @@ mod tests {
     fn test_invalid_add() {
         BigInt::from("").unwrap().add(&BigInt::from("456").unwrap());
     }
-}
\ No newline at end of file
+
+    #[test]
+    #[should_panic]
+    fn test_sign_only_is_invalid() {
+        BigInt::from("-").unwrap();
+    }
+
+    #[test]
+    fn test_negative_zero_is_canonical() {
+        assert_eq!(BigInt::from("-0").unwrap(), BigInt::zero());
+        assert!(!BigInt::from("-0").unwrap().is_negative());
+    }
+
+    #[test]
+    fn test_mul() {
+        assert_eq!(BigInt::from("123").unwrap().mul(&BigInt::from("456").unwrap()), BigInt::from("56088").unwrap());
+        assert_eq!(BigInt::from("123").unwrap().mul(&BigInt::from("-456").unwrap()), BigInt::from("-56088").unwrap());
+        assert_eq!(BigInt::from("-123").unwrap().mul(&BigInt::from("-456").unwrap()), BigInt::from("56088").unwrap());
+        assert_eq!(BigInt::from("0").unwrap().mul(&BigInt::from("456").unwrap()), BigInt::from("0").unwrap());
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let (q, r) = BigInt::from("100").unwrap().div_rem(&BigInt::from("4").unwrap()).unwrap();
+        assert_eq!(q, BigInt::from("25").unwrap());
+        assert_eq!(r, BigInt::from("0").unwrap());
+
+        let (q, r) = BigInt::from("17").unwrap().div_rem(&BigInt::from("5").unwrap()).unwrap();
+        assert_eq!(q, BigInt::from("3").unwrap());
+        assert_eq!(r, BigInt::from("2").unwrap());
+
+        // Truncated division: quotient rounds toward zero, remainder takes
+        // the dividend's sign.
+        let (q, r) = BigInt::from("-17").unwrap().div_rem(&BigInt::from("5").unwrap()).unwrap();
+        assert_eq!(q, BigInt::from("-3").unwrap());
+        assert_eq!(r, BigInt::from("-2").unwrap());
+
+        let (q, r) = BigInt::from("17").unwrap().div_rem(&BigInt::from("-5").unwrap()).unwrap();
+        assert_eq!(q, BigInt::from("-3").unwrap());
+        assert_eq!(r, BigInt::from("2").unwrap());
+    }
+
+    #[test]
+    fn test_operators() {
+        let a = BigInt::from("123").unwrap();
+        let b = BigInt::from("456").unwrap();
+        assert_eq!(&a + &b, BigInt::from("579").unwrap());
+        assert_eq!(&b - &a, BigInt::from("333").unwrap());
+        assert_eq!(&a * &b, BigInt::from("56088").unwrap());
+        assert_eq!(-&a, BigInt::from("-123").unwrap());
+        assert_eq!(-(-&a), a);
+    }
+
+    #[test]
+    fn test_ord() {
+        let mut v = vec![
+            BigInt::from("5").unwrap(),
+            BigInt::from("-10").unwrap(),
+            BigInt::from("0").unwrap(),
+            BigInt::from("-3").unwrap(),
+            BigInt::from("100").unwrap(),
+        ];
+        v.sort();
+        assert_eq!(v, vec![
+            BigInt::from("-10").unwrap(),
+            BigInt::from("-3").unwrap(),
+            BigInt::from("0").unwrap(),
+            BigInt::from("5").unwrap(),
+            BigInt::from("100").unwrap(),
+        ]);
+    }
+
+    #[test]
+    fn test_radix_round_trip() {
+        assert_eq!(BigInt::from_str_radix("ff", 16).unwrap(), BigInt::from("255").unwrap());
+        assert_eq!(BigInt::from_str_radix("FF", 16).unwrap(), BigInt::from("255").unwrap());
+        assert_eq!(BigInt::from_str_radix("-101", 2).unwrap(), BigInt::from("-5").unwrap());
+        assert_eq!(BigInt::from_str_radix("z", 36).unwrap(), BigInt::from("35").unwrap());
+
+        assert_eq!(BigInt::from("255").unwrap().to_str_radix(16), "ff");
+        assert_eq!(BigInt::from("-5").unwrap().to_str_radix(2), "-101");
+        assert_eq!(BigInt::from("0").unwrap().to_str_radix(16), "0");
+        assert_eq!(BigInt::from("35").unwrap().to_str_radix(36), "z");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_radix_digit() {
+        BigInt::from_str_radix("2", 2).unwrap();
+    }
+
+    #[test]
+    fn test_div_by_zero() {
+        assert!(BigInt::from("10").unwrap().div_rem(&BigInt::from("0").unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_mul_karatsuba() {
+        // 400 decimal digits is comfortably more than KARATSUBA_THRESHOLD
+        // limbs, so this exercises the Karatsuba path rather than schoolbook.
+        let a = BigInt::from(&"9".repeat(400)).unwrap();
+        let b = BigInt::from(&"7".repeat(400)).unwrap();
+        let expected = BigInt::from("77777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777777762222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222223").unwrap();
+        assert_eq!(a.mul(&b), expected);
+    }
+
+    #[test]
+    fn test_num_traits() {
+        assert!(BigInt::zero().is_zero());
+        assert!(!BigInt::one().is_zero());
+        assert_eq!(BigInt::one(), BigInt::from("1").unwrap());
+
+        assert_eq!(BigInt::from("-5").unwrap().abs(), BigInt::from("5").unwrap());
+        assert_eq!(BigInt::from("-5").unwrap().signum(), BigInt::from("-1").unwrap());
+        assert_eq!(BigInt::from("5").unwrap().signum(), BigInt::from("1").unwrap());
+        assert_eq!(BigInt::zero().signum(), BigInt::zero());
+        assert!(BigInt::from("5").unwrap().is_positive());
+        assert!(BigInt::from("-5").unwrap().is_negative());
+
+        assert_eq!(BigInt::from("3").unwrap().abs_sub(&BigInt::from("5").unwrap()), BigInt::zero());
+        assert_eq!(BigInt::from("5").unwrap().abs_sub(&BigInt::from("3").unwrap()), BigInt::from("2").unwrap());
+
+        assert_eq!(<BigInt as Num>::from_str_radix("ff", 16).unwrap(), BigInt::from("255").unwrap());
+
+        assert_eq!(BigInt::from("1").unwrap().checked_add(&BigInt::from("2").unwrap()), Some(BigInt::from("3").unwrap()));
+        assert_eq!(BigInt::from("5").unwrap().checked_sub(&BigInt::from("2").unwrap()), Some(BigInt::from("3").unwrap()));
+        assert_eq!(BigInt::from("5").unwrap().checked_mul(&BigInt::from("2").unwrap()), Some(BigInt::from("10").unwrap()));
+
+        // A negative dividend with a zero remainder should still compare
+        // equal to the canonical positive zero.
+        let (_, r) = BigInt::from("-10").unwrap().div_rem(&BigInt::from("5").unwrap()).unwrap();
+        assert_eq!(r, BigInt::zero());
+    }
+
+    #[test]
+    fn test_pow() {
+        assert_eq!(BigInt::from("2").unwrap().pow(10), BigInt::from("1024").unwrap());
+        assert_eq!(BigInt::from("-2").unwrap().pow(3), BigInt::from("-8").unwrap());
+        assert_eq!(BigInt::from("-2").unwrap().pow(2), BigInt::from("4").unwrap());
+        assert_eq!(BigInt::from("5").unwrap().pow(0), BigInt::from("1").unwrap());
+        assert_eq!(BigInt::from("2").unwrap().pow(64), BigInt::from("18446744073709551616").unwrap());
+    }
+
+    #[test]
+    fn test_sqrt() {
+        assert_eq!(BigInt::from("0").unwrap().sqrt().unwrap(), BigInt::from("0").unwrap());
+        assert_eq!(BigInt::from("1").unwrap().sqrt().unwrap(), BigInt::from("1").unwrap());
+        assert_eq!(BigInt::from("16").unwrap().sqrt().unwrap(), BigInt::from("4").unwrap());
+        assert_eq!(BigInt::from("15").unwrap().sqrt().unwrap(), BigInt::from("3").unwrap());
+        assert_eq!(BigInt::from("1000000").unwrap().sqrt().unwrap(), BigInt::from("1000").unwrap());
+
+        let big = BigInt::from("2").unwrap().pow(128);
+        assert_eq!(big.sqrt().unwrap(), BigInt::from("2").unwrap().pow(64));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sqrt_negative() {
+        BigInt::from("-4").unwrap().sqrt().unwrap();
+    }
+
+    #[test]
+    fn test_large_value_round_trip() {
+        // Exercises multi-limb carries in both the decimal parser and the
+        // base-10^9-chunked formatter.
+        let s = "123456789012345678901234567890123456789";
+        assert_eq!(BigInt::from(s).unwrap().to_string(), s);
+    }
+}